@@ -0,0 +1,57 @@
+/// Persisted `monster` config: the primary node address used by plain
+/// `run`/`test_cli_node` calls, plus every node registered via `init`
+/// (named with `--name`, or by its address otherwise) for `--compare` runs.
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// A node registered under a human-readable name, used by `--compare` to
+/// test the same query against multiple endpoints.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NamedNode {
+    pub name: String,
+    pub node: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    pub node_address: String,
+    #[serde(default)]
+    pub nodes: Vec<NamedNode>,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Config::default()
+    }
+
+    fn path() -> PathBuf {
+        let home = std::env::var("HOME")
+            .or_else(|_| std::env::var("USERPROFILE"))
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| std::env::temp_dir());
+
+        home.join(".monster").join("config.json")
+    }
+
+    /// Loads the saved config, falling back to a default (empty) one if
+    /// it doesn't exist yet or fails to parse.
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let path = Self::path();
+
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        if let Ok(contents) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(path, contents);
+        }
+    }
+}
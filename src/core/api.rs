@@ -1,43 +1,115 @@
 /// The `cascade_api` module contains functions for initializing and running the CLI.
 pub mod cascade_api {
-    use crate::config::Config;
+    use crate::config::{Config, NamedNode};
     use crate::core::{CliArgs, InitArgs};
     use crate::report::{Report, ReportData, ReportHeader};
-    use crate::service::http_web3;
     use crate::{error, info};
-    use web3::transports::Http;
+    use futures::future::BoxFuture;
+    use serde_json::Value;
+    use std::path::Path;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio::sync::{mpsc, Semaphore};
+    use web3::transports::{Http, Ipc, WebSocket};
     use web3::types::BlockNumber;
-    use web3::Transport;
-    use web3::Web3;
+    use web3::{Call, RequestId, Transport, Web3};
+
+    /// A transport that can be backed by HTTP, WebSocket or IPC, chosen at
+    /// runtime from the scheme of the configured node address.
+    ///
+    /// This lets `monster` point at a plain JSON-RPC endpoint, a persistent
+    /// websocket subscription, or a local IPC socket without the caller
+    /// having to know which one it is.
+    #[derive(Debug, Clone)]
+    pub enum NodeTransport {
+        Http(Http),
+        Ws(WebSocket),
+        Ipc(Ipc),
+    }
+
+    impl NodeTransport {
+        /// Connects to `node`, picking the transport from its URL scheme.
+        ///
+        /// * `http://` / `https://` -> HTTP
+        /// * `ws://` / `wss://` -> WebSocket
+        /// * `file://<path>` or any other value that isn't a recognised URL -> IPC socket path
+        pub async fn connect(node: &str) -> web3::Result<Self> {
+            if node.starts_with("http://") || node.starts_with("https://") {
+                Ok(NodeTransport::Http(Http::new(node)?))
+            } else if node.starts_with("ws://") || node.starts_with("wss://") {
+                Ok(NodeTransport::Ws(WebSocket::new(node).await?))
+            } else {
+                let path = node.strip_prefix("file://").unwrap_or(node);
+                Ok(NodeTransport::Ipc(Ipc::new(Path::new(path)).await?))
+            }
+        }
+    }
+
+    impl Transport for NodeTransport {
+        type Out = BoxFuture<'static, web3::error::Result<Value>>;
+
+        fn prepare(&self, method: &str, params: Vec<Value>) -> (RequestId, Call) {
+            match self {
+                NodeTransport::Http(t) => t.prepare(method, params),
+                NodeTransport::Ws(t) => t.prepare(method, params),
+                NodeTransport::Ipc(t) => t.prepare(method, params),
+            }
+        }
+
+        fn send(&self, id: RequestId, request: Call) -> Self::Out {
+            match self {
+                NodeTransport::Http(t) => Box::pin(t.send(id, request)),
+                NodeTransport::Ws(t) => Box::pin(t.send(id, request)),
+                NodeTransport::Ipc(t) => Box::pin(t.send(id, request)),
+            }
+        }
+    }
 
     /// Initializes the CLI with the provided arguments.
     ///
-    /// Uses the `Config` struct to save the node address.
+    /// Loads the existing config (rather than starting from a fresh
+    /// default) and registers the node under `config.nodes` by `args.name`
+    /// (falling back to the node address when no name is given) so it
+    /// shows up in `--compare` runs alongside any other nodes already
+    /// registered. Registering the same name again updates that entry's
+    /// address in place instead of adding a duplicate.
+    ///
+    /// The primary `node_address` (used by plain `run`/`test_cli_node`) is
+    /// only updated when `args.name` is absent — naming a node marks it as
+    /// an additional node for `--compare`, not a replacement for the
+    /// primary target.
     ///
     /// # Arguments
     ///
     /// * `args` - The initialization arguments.
     pub async fn initialise_cli(args: InitArgs) {
-        let mut config = Config::new();
+        let mut config = Config::load();
+
+        if args.name.is_none() {
+            config.node_address = args.node.clone();
+        }
+
+        let name = args.name.clone().unwrap_or_else(|| args.node.clone());
+        match config.nodes.iter_mut().find(|n| n.name == name) {
+            Some(existing) => existing.node = args.node.clone(),
+            None => config.nodes.push(NamedNode {
+                name,
+                node: args.node.clone(),
+            }),
+        }
 
-        config.node_address = args.node.clone();
         config.save();
     }
 
     /// Tests the connection to the saved node.
-    ///  
+    ///
     /// # Arguments
-    ///     
+    ///
     /// * `args` - The CLI arguments.
-    ///     
+    ///
     /// # Returns
     ///
     /// * `true` if the connection is successful, `false` otherwise.
-    ///    
-    /// # Panics
-    ///
-    /// * If the node is not a websocket node.
-    /// * If the node is not a HTTP node.
     pub async fn test_cli_node(args: CliArgs) {
         let node = Config::load().node_address;
 
@@ -46,18 +118,38 @@ pub mod cascade_api {
             return;
         }
 
-        // initialize the http transport
-        let web3s = http_web3(node.clone());
+        let transport = match NodeTransport::connect(&node).await {
+            Ok(transport) => transport,
+            Err(e) => {
+                error!("Failed to connect to node: {:?}", e);
+                return;
+            }
+        };
+        let web3s = Web3::new(transport);
 
         run_default_test(&web3s, args).await;
     }
 
     /// Runs the CLI with the provided arguments.
     ///
+    /// When `args.compare` is set, runs `run_compare` instead and executes
+    /// the same method against every node registered in the config. When
+    /// `args.load_test` is set, runs `run_load_test` instead and issues a
+    /// concurrent, rate-limited batch of requests rather than a single
+    /// query.
+    ///
     /// # Arguments
     ///
     /// * `args` - The CLI arguments.
     pub async fn run(args: CliArgs) {
+        if args.compare {
+            return run_compare(args).await;
+        }
+
+        if args.load_test {
+            return run_load_test(args).await;
+        }
+
         let node = Config::load().node_address;
 
         if node.is_empty() {
@@ -65,23 +157,140 @@ pub mod cascade_api {
             return;
         }
 
-        // initialize the http transport
-        let web3s = http_web3(node.clone());
+        let transport = match NodeTransport::connect(&node).await {
+            Ok(transport) => transport,
+            Err(e) => {
+                error!("Failed to connect to node: {:?}", e);
+                return;
+            }
+        };
+        let web3s = Web3::new(transport);
+
+        run_with_query(&web3s, args).await;
+    }
+
+    /// Runs the same method against every node registered in the config and
+    /// produces a single side-by-side `Report` (one `ReportHeader` per
+    /// node), so a freshly-spun chain's responses can be compared against
+    /// a reference node for divergence.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The CLI arguments.
+    pub async fn run_compare(args: CliArgs) {
+        let nodes = Config::load().nodes;
+
+        if nodes.is_empty() {
+            error!("No nodes registered. Use 'init' command to register named nodes.");
+            return;
+        }
+
+        let params_serde = match build_params(&args) {
+            Ok(params) => params,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
+
+        let mut entries = Vec::with_capacity(nodes.len());
+
+        for NamedNode { name, node } in nodes {
+            let header = ReportHeader {
+                node: name.clone(),
+                args: args.clone(),
+            };
+
+            let transport = match NodeTransport::connect(&node).await {
+                Ok(transport) => transport,
+                Err(e) => {
+                    error!("[{}] failed to connect: {:?}", name, e);
+                    entries.push((
+                        header,
+                        ReportData {
+                            success: false,
+                            error: Some(format!("{:?}", e)),
+                            duration: 0,
+                            result: None,
+                        },
+                    ));
+                    continue;
+                }
+            };
+
+            let started = Instant::now();
+            let result = transport.execute(&args.method, params_serde.clone()).await;
+            let duration = started.elapsed().as_millis() as u64;
+
+            let data = match result {
+                Ok(value) => ReportData {
+                    success: true,
+                    error: None,
+                    duration,
+                    result: Some(value.to_string()),
+                },
+                Err(e) => {
+                    error!("[{}] error: {:?}", name, e);
+                    ReportData {
+                        success: false,
+                        error: Some(format!("{:?}", e)),
+                        duration,
+                        result: None,
+                    }
+                }
+            };
+
+            entries.push((header, data));
+        }
+
+        let report = Report::compare(entries);
 
-        run_with_query_http(&web3s, args).await;
+        report.output(&args.format, args.output.as_deref());
+    }
+
+    /// Runs a concurrent, rate-limited load test against the saved node.
+    ///
+    /// Uses `args.concurrency` to size a `Semaphore` capping in-flight
+    /// requests and `args.rate` to throttle how many requests are issued
+    /// per second, firing `args.samples` total requests. The resulting
+    /// `Report` lists each sample's success/failure and latency plus the
+    /// latency percentiles; achieved throughput and error rate are not
+    /// computed.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The CLI arguments.
+    pub async fn run_load_test(args: CliArgs) {
+        let node = Config::load().node_address;
+
+        if node.is_empty() {
+            error!("Node address not initialized. Use 'init' command to set the node.");
+            return;
+        }
+
+        let transport = match NodeTransport::connect(&node).await {
+            Ok(transport) => transport,
+            Err(e) => {
+                error!("Failed to connect to node: {:?}", e);
+                return;
+            }
+        };
+        let web3s = Web3::new(transport);
+
+        load_test(&web3s, args).await;
     }
 
     /// Runs the default test when the address is not provided.
     ///
     /// # Arguments
     ///
-    /// * `web3_http` - The HTTP Web3 instance.
+    /// * `web3` - The Web3 instance backed by the selected transport.
     /// * `args` - The CLI arguments.
-    async fn run_default_test(web3_http: &Web3<Http>, args: CliArgs) {
+    async fn run_default_test(web3: &Web3<NodeTransport>, args: CliArgs) {
         let from_block = BlockNumber::Number(args.from.into());
         let to_block = BlockNumber::Number(args.to.into());
 
-        let logs = web3_http
+        let logs = web3
             .eth()
             .logs(
                 web3::types::FilterBuilder::default()
@@ -95,18 +304,90 @@ pub mod cascade_api {
         if args.method == "logs" {
             info!("Logs length: {:?}", logs.len());
         } else {
-            run_with_query_http(&web3_http, args).await;
+            run_with_query(web3, args).await;
         }
     }
 
-    /// Runs the HTTP test with query parameters.
+    /// Builds the JSON-RPC params for `args.method`.
+    ///
+    /// If `args.params` is set, it is parsed as raw JSON and passed straight
+    /// through (an array is used as-is, any other value is wrapped in a
+    /// single-element array), bypassing the registry entirely. Otherwise
+    /// `args.method` is looked up in a small registry of common `eth_*`/
+    /// `net_*` calls and its params are built from the address/block range
+    /// flags, returning an error if a flag that method requires is missing.
+    fn build_params(args: &CliArgs) -> Result<Vec<Value>, String> {
+        if let Some(raw) = &args.params {
+            let value: Value =
+                serde_json::from_str(raw).map_err(|e| format!("invalid --params JSON: {:?}", e))?;
+            return Ok(match value {
+                Value::Array(values) => values,
+                other => vec![other],
+            });
+        }
+
+        match args.method.as_str() {
+            "eth_getLogs" | "logs" => Ok(vec![serde_json::json!({
+                "fromBlock": args.from,
+                "toBlock": args.to,
+                "address": hex_address(&args.address),
+            })]),
+            "eth_getBlockByNumber" => Ok(vec![
+                serde_json::json!(format!("0x{:x}", args.from)),
+                serde_json::json!(true),
+            ]),
+            "eth_call" => {
+                if args.address == "0x0" {
+                    return Err("eth_call requires --address to be set".to_string());
+                }
+                Ok(vec![
+                    serde_json::json!({ "to": hex_address(&args.address) }),
+                    serde_json::json!(format!("0x{:x}", args.to)),
+                ])
+            }
+            "eth_getBalance" => {
+                if args.address == "0x0" {
+                    return Err("eth_getBalance requires --address to be set".to_string());
+                }
+                Ok(vec![
+                    serde_json::json!(hex_address(&args.address)),
+                    serde_json::json!(format!("0x{:x}", args.to)),
+                ])
+            }
+            "eth_blockNumber" | "net_version" => Ok(vec![]),
+            other => Err(format!(
+                "unsupported method '{}', pass raw params with --params instead",
+                other
+            )),
+        }
+    }
+
+    /// Formats `address` as a `0x`-prefixed hex string, stripping any
+    /// leading `0x`/`0X` first so an already-prefixed address (the normal,
+    /// copy-pasted case) isn't double-prefixed into an invalid
+    /// `"0x0x..."` value.
+    fn hex_address(address: &str) -> String {
+        format!(
+            "0x{}",
+            address
+                .strip_prefix("0x")
+                .or_else(|| address.strip_prefix("0X"))
+                .unwrap_or(address)
+        )
+    }
+
+    /// Runs the test with the query parameters, dispatching through whichever
+    /// transport (HTTP, WebSocket or IPC) the node address resolved to.
+    ///
+    /// Fires `args.samples` requests back to back so `Report` can summarize
+    /// the latency distribution rather than a single success/failure.
     ///
     /// # Arguments
     ///
-    /// * `web3_http` - The HTTP Web3 instance.
+    /// * `web3` - The Web3 instance backed by the selected transport.
     /// * `args` - The CLI arguments.
-    async fn run_with_query_http(web3_http: &Web3<Http>, args: CliArgs) {
-        let transport = web3_http.transport();
+    async fn run_with_query(web3: &Web3<NodeTransport>, args: CliArgs) {
+        let transport = web3.transport();
 
         let header = ReportHeader {
             node: "node".to_string(),
@@ -115,34 +396,249 @@ pub mod cascade_api {
 
         print!("Running with query parameters: {:?}", args.params);
 
-        // let params_serde = vec![helpers::serialize(&args.params)];
-        let params_serde = vec![serde_json::json!({
-            "fromBlock": args.from,
-            "toBlock": args.to,
-            "address": format!("0x{}", args.address),
-        })];
+        let params_serde = match build_params(&args) {
+            Ok(params) => params,
+            Err(e) => {
+                error!("{}", e);
+                return;
+            }
+        };
 
-        let get_logs = transport.execute(&args.method, params_serde).await;
+        let mut report = Report::new(header);
 
-        match get_logs {
-            Ok(_) => {
-                let data = [ReportData {
+        for _ in 0..args.samples.max(1) {
+            let started = Instant::now();
+            let get_logs = transport.execute(&args.method, params_serde.clone()).await;
+            let duration = started.elapsed().as_millis() as u64;
+
+            let data = match get_logs {
+                Ok(_) => ReportData {
                     success: true,
                     error: None,
-                    duration: 0,
+                    duration,
                     result: Some("Success".to_string()),
-                }]
-                .to_vec();
+                },
+                Err(e) => {
+                    error!("Error: {:?}", e);
+                    ReportData {
+                        success: false,
+                        error: Some(format!("{:?}", e)),
+                        duration,
+                        result: None,
+                    }
+                }
+            };
 
-                let mut report = Report::new(header);
+            report.add_data(data);
+        }
 
-                report.add_data(data[0].clone());
+        report.output(&args.format, args.output.as_deref());
+    }
 
-                report.display();
-            }
+    /// Issues `args.samples` requests against `web3`, capping in-flight
+    /// requests at `args.concurrency` via a semaphore and throttling
+    /// issuance to `args.rate` requests/sec via a simple token-bucket
+    /// interval. Every sample's `ReportData` (success/failure + latency)
+    /// is collected through an mpsc channel and summarized by `Report`.
+    async fn load_test(web3: &Web3<NodeTransport>, args: CliArgs) {
+        let transport = web3.transport().clone();
+
+        let header = ReportHeader {
+            node: "node".to_string(),
+            args: args.clone(),
+        };
+
+        let params_serde = match build_params(&args) {
+            Ok(params) => params,
             Err(e) => {
-                error!("Error: {:?}", e);
+                error!("{}", e);
+                return;
             }
+        };
+
+        let total = args.samples.max(1);
+        let semaphore = Arc::new(Semaphore::new(args.concurrency.max(1) as usize));
+        let mut ticker =
+            tokio::time::interval(Duration::from_secs_f64(1.0 / args.rate.max(1) as f64));
+        // Default `Burst` behavior fires every missed tick back to back once the
+        // semaphore backs up issuance, which would exceed `--rate` under load.
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let (tx, mut rx) = mpsc::channel::<ReportData>(total as usize);
+
+        for _ in 0..total {
+            ticker.tick().await;
+
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("semaphore should never be closed");
+            let transport = transport.clone();
+            let method = args.method.clone();
+            let params_serde = params_serde.clone();
+            let tx = tx.clone();
+
+            tokio::spawn(async move {
+                let _permit = permit;
+
+                let started = Instant::now();
+                let result = transport.execute(&method, params_serde).await;
+                let duration = started.elapsed().as_millis() as u64;
+
+                let data = match result {
+                    Ok(_) => ReportData {
+                        success: true,
+                        error: None,
+                        duration,
+                        result: Some("Success".to_string()),
+                    },
+                    Err(e) => ReportData {
+                        success: false,
+                        error: Some(format!("{:?}", e)),
+                        duration,
+                        result: None,
+                    },
+                };
+
+                let _ = tx.send(data).await;
+            });
+        }
+
+        drop(tx);
+
+        let mut report = Report::new(header);
+
+        while let Some(data) = rx.recv().await {
+            report.add_data(data);
+        }
+
+        report.output(&args.format, args.output.as_deref());
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use clap::Parser;
+
+        fn args(extra: &[&str]) -> CliArgs {
+            let mut argv = vec!["monster"];
+            argv.extend_from_slice(extra);
+            CliArgs::parse_from(argv)
+        }
+
+        #[test]
+        fn build_params_defaults_to_eth_get_logs_shape() {
+            let params = build_params(&args(&["--method", "eth_getLogs"])).unwrap();
+
+            assert_eq!(params.len(), 1);
+            assert!(params[0].get("fromBlock").is_some());
+            assert!(params[0].get("toBlock").is_some());
+            assert!(params[0].get("address").is_some());
+        }
+
+        #[test]
+        fn build_params_rejects_eth_call_without_address() {
+            let err = build_params(&args(&["--method", "eth_call"])).unwrap_err();
+
+            assert!(err.contains("--address"));
+        }
+
+        #[test]
+        fn build_params_accepts_eth_call_with_address() {
+            let params =
+                build_params(&args(&["--method", "eth_call", "--address", "deadbeef"])).unwrap();
+
+            assert_eq!(params[0]["to"], "0xdeadbeef");
+        }
+
+        #[test]
+        fn build_params_does_not_double_prefix_an_already_prefixed_address() {
+            let params =
+                build_params(&args(&["--method", "eth_call", "--address", "0xdeadbeef"])).unwrap();
+
+            assert_eq!(params[0]["to"], "0xdeadbeef");
+        }
+
+        #[test]
+        fn build_params_rejects_unsupported_method() {
+            let err = build_params(&args(&["--method", "totally_made_up"])).unwrap_err();
+
+            assert!(err.contains("totally_made_up"));
+        }
+
+        #[test]
+        fn build_params_passes_through_raw_array_params() {
+            let params = build_params(&args(&[
+                "--method",
+                "eth_blockNumber",
+                "--params",
+                "[1,2,3]",
+            ]))
+            .unwrap();
+
+            assert_eq!(
+                params,
+                vec![
+                    serde_json::json!(1),
+                    serde_json::json!(2),
+                    serde_json::json!(3)
+                ]
+            );
+        }
+
+        #[test]
+        fn build_params_wraps_raw_non_array_params() {
+            let params = build_params(&args(&[
+                "--method",
+                "eth_blockNumber",
+                "--params",
+                "{\"foo\":1}",
+            ]))
+            .unwrap();
+
+            assert_eq!(params, vec![serde_json::json!({"foo": 1})]);
+        }
+
+        #[test]
+        fn build_params_rejects_invalid_raw_json() {
+            let err = build_params(&args(&[
+                "--method",
+                "eth_blockNumber",
+                "--params",
+                "not json",
+            ]))
+            .unwrap_err();
+
+            assert!(err.contains("--params"));
+        }
+
+        #[tokio::test]
+        async fn node_transport_connect_picks_http_for_http_scheme() {
+            let transport = NodeTransport::connect("http://localhost:8545")
+                .await
+                .unwrap();
+
+            assert!(matches!(transport, NodeTransport::Http(_)));
+        }
+
+        #[tokio::test]
+        async fn node_transport_connect_picks_http_for_https_scheme() {
+            let transport = NodeTransport::connect("https://example.com").await.unwrap();
+
+            assert!(matches!(transport, NodeTransport::Http(_)));
+        }
+
+        #[tokio::test]
+        async fn node_transport_connect_falls_back_to_ipc_for_non_url_paths() {
+            // No socket is listening at this path, so the connection itself
+            // fails, but it should still be dispatched to the IPC variant
+            // rather than panicking like the old hardcoded-HTTP path did.
+            // Only the IPC path surfaces the socket's I/O error; HTTP and
+            // WebSocket reject a non-URL as a transport (parse) error instead.
+            let result = NodeTransport::connect("/tmp/does-not-exist.ipc").await;
+
+            assert!(result.is_err());
+            assert!(matches!(result, Err(web3::Error::Io(_))));
         }
     }
 }
@@ -1,7 +1,17 @@
 use crate::core::cli_controller::run;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use std::path::PathBuf;
 
-#[derive(Clone, Debug, Parser)]
+/// Output format for a finished `Report`.
+#[derive(Clone, Debug, ValueEnum, Serialize)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Debug, Parser, Serialize)]
 #[command(author = "Rhaqim <anusiemj@gmail.com>", version = "0.1")]
 #[command(
     about = "monster - a simple CLI to test nodes",
@@ -30,10 +40,75 @@ pub struct CliArgs {
         help = "Timeout for the request"
     )]
     pub timeout: u64,
+    #[arg(
+        long,
+        short = 's',
+        default_value = "1",
+        help = "Number of times to repeat the request, reporting latency percentiles across the batch"
+    )]
+    pub samples: u32,
+    #[arg(
+        long,
+        short = 'c',
+        default_value = "1",
+        help = "Max in-flight requests when running with --load-test"
+    )]
+    pub concurrency: u32,
+    #[arg(
+        long,
+        short = 'r',
+        default_value = "100",
+        help = "Max requests issued per second when running with --load-test"
+    )]
+    pub rate: u32,
+    #[arg(
+        long,
+        short = 'p',
+        help = "Raw JSON-RPC params to send, bypassing the method's built-in param builder"
+    )]
+    pub params: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value = "text",
+        help = "Report output format"
+    )]
+    pub format: ReportFormat,
+    #[arg(
+        long,
+        short = 'o',
+        help = "Write the report to this file instead of stdout"
+    )]
+    pub output: Option<PathBuf>,
+    #[arg(
+        long,
+        alias = "all",
+        help = "Run the query against every node registered in the config and compare results side by side"
+    )]
+    pub compare: bool,
+    #[arg(
+        long,
+        help = "Run a concurrent, rate-limited load test instead of a single query (see --samples, --concurrency, --rate)"
+    )]
+    pub load_test: bool,
+}
+
+/// Arguments for the `init` command, used to register a node (optionally
+/// under a human-readable name) in the saved config.
+#[derive(Clone, Debug, Parser)]
+pub struct InitArgs {
+    #[arg(long, short, help = "Node address to register")]
+    pub node: String,
+    #[arg(
+        long,
+        short = 'N',
+        help = "Human-readable name for this node, used to label it in --compare reports (defaults to the node address)"
+    )]
+    pub name: Option<String>,
 }
 
 pub async fn cli_main() {
     let cli = CliArgs::parse();
 
     run(cli).await;
-}
\ No newline at end of file
+}
@@ -0,0 +1,199 @@
+/// Report types shared by every `monster` run: a `ReportHeader` describing
+/// what was tested, a `ReportData` per request sample, and a `Report` that
+/// accumulates samples (grouped into one `ReportSection` per node) and
+/// summarizes their latency distribution.
+use crate::core::{CliArgs, ReportFormat};
+use serde::Serialize;
+use std::path::Path;
+
+/// Static context for a report section: which node was hit and with what
+/// CLI arguments.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReportHeader {
+    pub node: String,
+    pub args: CliArgs,
+}
+
+/// The outcome of a single request.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReportData {
+    pub success: bool,
+    pub error: Option<String>,
+    pub duration: u64,
+    pub result: Option<String>,
+}
+
+/// Aggregate latency stats (in milliseconds) across a batch of samples.
+#[derive(Clone, Debug, Serialize)]
+pub struct LatencyStats {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+}
+
+impl LatencyStats {
+    /// Computes min/max/mean/p50/p90/p99 across `samples`' durations.
+    /// Returns `None` for an empty batch.
+    fn from_samples(samples: &[ReportData]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut durations: Vec<u64> = samples.iter().map(|s| s.duration).collect();
+        durations.sort_unstable();
+
+        let len = durations.len();
+        let percentile = |p: f64| durations[(((len - 1) as f64) * p).round() as usize];
+
+        Some(LatencyStats {
+            min: durations[0],
+            max: durations[len - 1],
+            mean: durations.iter().sum::<u64>() as f64 / len as f64,
+            p50: percentile(0.50),
+            p90: percentile(0.90),
+            p99: percentile(0.99),
+        })
+    }
+}
+
+/// One node's worth of samples in a `Report`: its header, every sample
+/// collected for it, and the aggregate latency stats across those samples.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReportSection {
+    pub header: ReportHeader,
+    pub samples: Vec<ReportData>,
+    pub latency: Option<LatencyStats>,
+}
+
+/// A finished test/benchmark/comparison run: one or more `ReportSection`s.
+/// A single-node `run` produces a `Report` with one section; `run_compare`
+/// produces one section per configured node so they render side by side.
+#[derive(Clone, Debug, Serialize)]
+pub struct Report {
+    pub sections: Vec<ReportSection>,
+}
+
+impl Report {
+    /// Starts a single-section report for `header`, with no samples yet.
+    pub fn new(header: ReportHeader) -> Self {
+        Report {
+            sections: vec![ReportSection {
+                header,
+                samples: Vec::new(),
+                latency: None,
+            }],
+        }
+    }
+
+    /// Builds a side-by-side report with one section per `(header, data)`
+    /// entry, as produced by a `--compare` run across multiple nodes.
+    pub fn compare(entries: Vec<(ReportHeader, ReportData)>) -> Self {
+        let sections = entries
+            .into_iter()
+            .map(|(header, data)| {
+                let latency = LatencyStats::from_samples(std::slice::from_ref(&data));
+                ReportSection {
+                    header,
+                    samples: vec![data],
+                    latency,
+                }
+            })
+            .collect();
+
+        Report { sections }
+    }
+
+    /// Records a sample against this report's (only) section and
+    /// recomputes that section's latency stats. Only meaningful for
+    /// single-section reports built with `Report::new`.
+    pub fn add_data(&mut self, data: ReportData) {
+        if let Some(section) = self.sections.first_mut() {
+            section.samples.push(data);
+            section.latency = LatencyStats::from_samples(&section.samples);
+        }
+    }
+
+    /// Renders the report in `format`, writing to `output` if given or
+    /// printing to stdout otherwise.
+    pub fn output(&self, format: &ReportFormat, output: Option<&Path>) {
+        let rendered = match format {
+            ReportFormat::Text => self.render_text(),
+            ReportFormat::Json => self.render_json(),
+            ReportFormat::Csv => self.render_csv(),
+        };
+
+        match output {
+            Some(path) => {
+                if let Err(e) = std::fs::write(path, rendered) {
+                    eprintln!("failed to write report to {}: {:?}", path.display(), e);
+                }
+            }
+            None => println!("{}", rendered),
+        }
+    }
+
+    fn render_text(&self) -> String {
+        let mut out = String::new();
+
+        for section in &self.sections {
+            out.push_str(&format!("node: {}\n", section.header.node));
+
+            for (i, sample) in section.samples.iter().enumerate() {
+                out.push_str(&format!(
+                    "  #{}: success={} duration={}ms result={:?} error={:?}\n",
+                    i + 1,
+                    sample.success,
+                    sample.duration,
+                    sample.result,
+                    sample.error
+                ));
+            }
+
+            if let Some(latency) = &section.latency {
+                out.push_str(&format!(
+                    "  latency(ms): min={} max={} mean={:.2} p50={} p90={} p99={}\n",
+                    latency.min, latency.max, latency.mean, latency.p50, latency.p90, latency.p99
+                ));
+            }
+        }
+
+        out
+    }
+
+    fn render_json(&self) -> String {
+        serde_json::to_string_pretty(self)
+            .unwrap_or_else(|e| format!("{{\"error\": \"failed to serialize report: {:?}\"}}", e))
+    }
+
+    fn render_csv(&self) -> String {
+        let mut out = String::from("node,method,success,duration_ms,result,error\n");
+
+        for section in &self.sections {
+            for sample in &section.samples {
+                out.push_str(&format!(
+                    "{},{},{},{},{},{}\n",
+                    csv_field(&section.header.node),
+                    csv_field(&section.header.args.method),
+                    sample.success,
+                    sample.duration,
+                    csv_field(sample.result.as_deref().unwrap_or("")),
+                    csv_field(sample.error.as_deref().unwrap_or("")),
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+/// Quotes `value` for CSV if it contains a comma, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}